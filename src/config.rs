@@ -0,0 +1,429 @@
+//! Loading keymaps from a TOML config file instead of hard-coding them in Rust.
+//! Only TOML is parsed; JSON config text is not accepted.
+//!
+//! Since systems can't be deserialized directly, bindings in a config file name an
+//! *action* (a string) rather than a system. The action is resolved against an
+//! [`ActionRegistry<T>`] that the app populates ahead of time with
+//! [`ActionRegistry::register_action`].
+
+use crate::{Keymap, Keymapper, Modifiers, Trigger};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// A resource mapping action names to the systems they run.
+///
+/// Scoped by the same label type `T` as [`Keymapper<T>`], so different keymap
+/// domains (e.g. debug vs. release bindings) can reuse action names without colliding.
+/// Resolving a config entry takes its system out of the registry, so each
+/// registered action can back exactly one config-loaded keymap.
+#[derive(Resource)]
+pub struct ActionRegistry<T: Send + Sync + 'static> {
+    actions: HashMap<String, Box<dyn System<In = (), Out = ()>>>,
+    _label: PhantomData<T>,
+}
+
+impl<T: Send + Sync + 'static> Default for ActionRegistry<T> {
+    fn default() -> Self {
+        Self {
+            actions: HashMap::new(),
+            _label: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> ActionRegistry<T> {
+    /// Creates an empty action registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a system under `name` so config entries can bind a key to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The action name config entries refer to via their `action` field.
+    /// * `system` - The system to run when a keymap resolved from `name` fires.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the registry for method chaining.
+    pub fn register_action<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self {
+        self.actions
+            .insert(name.into(), Box::new(IntoSystem::into_system(system)));
+        self
+    }
+
+    /// Returns whether an action named `name` is currently registered.
+    fn contains(&self, name: &str) -> bool {
+        self.actions.contains_key(name)
+    }
+
+    /// Removes and returns the system registered under `name`, if any.
+    fn take(&mut self, name: &str) -> Option<Box<dyn System<In = (), Out = ()>>> {
+        self.actions.remove(name)
+    }
+}
+
+/// A single `{ key, action, label }` binding loaded from a config file.
+#[derive(Debug, Deserialize)]
+pub struct KeymapConfigEntry {
+    /// The key name, e.g. `"Space"` or `"KeyW"` (matching the `KeyCode` variant name).
+    pub key: String,
+    /// The action name to resolve against the app's [`ActionRegistry<T>`].
+    pub action: String,
+    /// A user-facing label, parsed into the keymap's label type `T`.
+    pub label: String,
+}
+
+/// The top-level shape of a keymap config file.
+#[derive(Debug, Deserialize)]
+pub struct KeymapConfig {
+    /// The bindings defined in this config.
+    pub keymaps: Vec<KeymapConfigEntry>,
+}
+
+/// An error produced while loading or resolving a [`KeymapConfig`].
+#[derive(Debug)]
+pub enum KeymapConfigError {
+    /// The config file could not be read from disk.
+    Io(std::io::Error),
+    /// The config text could not be parsed as TOML.
+    Parse(toml::de::Error),
+    /// A binding's `key` did not match a known `KeyCode` variant name.
+    UnknownKey(String),
+    /// A binding's `action` was not registered in the app's `ActionRegistry<T>`.
+    UnknownAction(String),
+    /// An `action` was named by more than one binding in the same config; each
+    /// registered action can only back one config-loaded keymap.
+    DuplicateAction(String),
+    /// A binding's `label` could not be parsed into the keymap's label type.
+    InvalidLabel(String),
+}
+
+impl fmt::Display for KeymapConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read keymap config: {e}"),
+            Self::Parse(e) => write!(f, "failed to parse keymap config: {e}"),
+            Self::UnknownKey(key) => write!(f, "unknown key `{key}` in keymap config"),
+            Self::UnknownAction(action) => {
+                write!(
+                    f,
+                    "action `{action}` is not registered in the ActionRegistry"
+                )
+            }
+            Self::DuplicateAction(action) => {
+                write!(
+                    f,
+                    "action `{action}` is bound by more than one keymap entry"
+                )
+            }
+            Self::InvalidLabel(label) => write!(f, "label `{label}` could not be parsed"),
+        }
+    }
+}
+
+impl std::error::Error for KeymapConfigError {}
+
+impl From<std::io::Error> for KeymapConfigError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for KeymapConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// An extension trait for `App` that loads keymaps from a config file.
+pub trait KeymapperConfigAppExt {
+    /// Parses `config` as TOML and pushes a resolved [`Keymap`] for each entry,
+    /// looking up its `action` in the app's `ActionRegistry<T>` (creating an empty
+    /// one if absent) and parsing its `label` into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeymapConfigError`] if the config fails to parse, an entry's
+    /// `key` isn't a known `KeyCode` name, its `action` isn't registered (or is
+    /// named by more than one entry), or its `label` fails to parse into `T`.
+    fn load_keymaps_from_str<T>(&mut self, config: &str) -> Result<&mut Self, KeymapConfigError>
+    where
+        T: Send + Sync + PartialEq + std::str::FromStr + 'static;
+
+    /// Reads the file at `path` and loads it the same way as [`load_keymaps_from_str`](
+    /// KeymapperConfigAppExt::load_keymaps_from_str).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeymapConfigError`] if the file can't be read, or for any of the
+    /// reasons `load_keymaps_from_str` can.
+    fn load_keymaps_from_path<T>(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<&mut Self, KeymapConfigError>
+    where
+        T: Send + Sync + PartialEq + std::str::FromStr + 'static;
+}
+
+impl KeymapperConfigAppExt for App {
+    fn load_keymaps_from_str<T>(&mut self, config: &str) -> Result<&mut Self, KeymapConfigError>
+    where
+        T: Send + Sync + PartialEq + std::str::FromStr + 'static,
+    {
+        let config: KeymapConfig = toml::from_str(config)?;
+
+        if !self.world().contains_resource::<Keymapper<T>>() {
+            self.insert_resource(Keymapper::<T>::new(vec![]));
+        }
+        if !self.world().contains_resource::<ActionRegistry<T>>() {
+            self.insert_resource(ActionRegistry::<T>::new());
+        }
+
+        // Validate every entry, without mutating `ActionRegistry`/`Keymapper`, before
+        // resolving any of them. This keeps a bad entry from leaving earlier good
+        // entries half-applied (action taken out of the registry but its keymap
+        // never pushed, or vice versa). Checking for a duplicate action name here,
+        // rather than letting the second `take` on it fail below, means resolution
+        // can no longer fail partway through and lose an already-taken system.
+        let registry = self.world().resource::<ActionRegistry<T>>();
+        let mut seen_actions = HashSet::with_capacity(config.keymaps.len());
+        let mut checked = Vec::with_capacity(config.keymaps.len());
+        for entry in config.keymaps {
+            let keycode = parse_keycode(&entry.key)
+                .ok_or_else(|| KeymapConfigError::UnknownKey(entry.key.clone()))?;
+            let label = entry
+                .label
+                .parse::<T>()
+                .map_err(|_| KeymapConfigError::InvalidLabel(entry.label.clone()))?;
+            if !registry.contains(&entry.action) {
+                return Err(KeymapConfigError::UnknownAction(entry.action));
+            }
+            if !seen_actions.insert(entry.action.clone()) {
+                return Err(KeymapConfigError::DuplicateAction(entry.action));
+            }
+            checked.push((label, keycode, entry.action));
+        }
+
+        // Every entry is valid and resolves to a distinct action, so taking them out
+        // of the registry now can't fail.
+        let mut registry = self.world_mut().resource_mut::<ActionRegistry<T>>();
+        let mut resolved = Vec::with_capacity(checked.len());
+        for (label, keycode, action) in checked {
+            let system = registry
+                .take(&action)
+                .expect("validated above: action exists and is only taken once");
+            resolved.push((label, keycode, system));
+        }
+
+        let mut manager = self.world_mut().resource_mut::<Keymapper<T>>();
+        for (label, keycode, system) in resolved {
+            manager.keymaps.push(Keymap::new_with_trigger(
+                label,
+                vec![keycode],
+                Modifiers::NONE,
+                Trigger::JustPressed,
+                system,
+            ));
+        }
+
+        Ok(self)
+    }
+
+    fn load_keymaps_from_path<T>(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<&mut Self, KeymapConfigError>
+    where
+        T: Send + Sync + PartialEq + std::str::FromStr + 'static,
+    {
+        let config = std::fs::read_to_string(path)?;
+        self.load_keymaps_from_str::<T>(&config)
+    }
+}
+
+/// Parses a `KeyCode` variant name (e.g. `"Space"`, `"KeyW"`, `"ControlLeft"`).
+///
+/// Covers the variants a config file is likely to name: letters, digits, function
+/// keys, arrows, modifiers and the common whitespace/editing keys. Returns `None`
+/// for anything else, including valid but uncommon `KeyCode` variants.
+fn parse_keycode(name: &str) -> Option<KeyCode> {
+    Some(match name {
+        "KeyA" => KeyCode::KeyA,
+        "KeyB" => KeyCode::KeyB,
+        "KeyC" => KeyCode::KeyC,
+        "KeyD" => KeyCode::KeyD,
+        "KeyE" => KeyCode::KeyE,
+        "KeyF" => KeyCode::KeyF,
+        "KeyG" => KeyCode::KeyG,
+        "KeyH" => KeyCode::KeyH,
+        "KeyI" => KeyCode::KeyI,
+        "KeyJ" => KeyCode::KeyJ,
+        "KeyK" => KeyCode::KeyK,
+        "KeyL" => KeyCode::KeyL,
+        "KeyM" => KeyCode::KeyM,
+        "KeyN" => KeyCode::KeyN,
+        "KeyO" => KeyCode::KeyO,
+        "KeyP" => KeyCode::KeyP,
+        "KeyQ" => KeyCode::KeyQ,
+        "KeyR" => KeyCode::KeyR,
+        "KeyS" => KeyCode::KeyS,
+        "KeyT" => KeyCode::KeyT,
+        "KeyU" => KeyCode::KeyU,
+        "KeyV" => KeyCode::KeyV,
+        "KeyW" => KeyCode::KeyW,
+        "KeyX" => KeyCode::KeyX,
+        "KeyY" => KeyCode::KeyY,
+        "KeyZ" => KeyCode::KeyZ,
+        "Digit0" => KeyCode::Digit0,
+        "Digit1" => KeyCode::Digit1,
+        "Digit2" => KeyCode::Digit2,
+        "Digit3" => KeyCode::Digit3,
+        "Digit4" => KeyCode::Digit4,
+        "Digit5" => KeyCode::Digit5,
+        "Digit6" => KeyCode::Digit6,
+        "Digit7" => KeyCode::Digit7,
+        "Digit8" => KeyCode::Digit8,
+        "Digit9" => KeyCode::Digit9,
+        "ArrowUp" => KeyCode::ArrowUp,
+        "ArrowDown" => KeyCode::ArrowDown,
+        "ArrowLeft" => KeyCode::ArrowLeft,
+        "ArrowRight" => KeyCode::ArrowRight,
+        "Space" => KeyCode::Space,
+        "Enter" => KeyCode::Enter,
+        "Escape" => KeyCode::Escape,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "ControlLeft" => KeyCode::ControlLeft,
+        "ControlRight" => KeyCode::ControlRight,
+        "AltLeft" => KeyCode::AltLeft,
+        "AltRight" => KeyCode::AltRight,
+        "ShiftLeft" => KeyCode::ShiftLeft,
+        "ShiftRight" => KeyCode::ShiftRight,
+        "SuperLeft" => KeyCode::SuperLeft,
+        "SuperRight" => KeyCode::SuperRight,
+        "F1" => KeyCode::F1,
+        "F2" => KeyCode::F2,
+        "F3" => KeyCode::F3,
+        "F4" => KeyCode::F4,
+        "F5" => KeyCode::F5,
+        "F6" => KeyCode::F6,
+        "F7" => KeyCode::F7,
+        "F8" => KeyCode::F8,
+        "F9" => KeyCode::F9,
+        "F10" => KeyCode::F10,
+        "F11" => KeyCode::F11,
+        "F12" => KeyCode::F12,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Label {
+        Jump,
+    }
+
+    impl std::str::FromStr for Label {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "Jump" => Ok(Label::Jump),
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn noop() {}
+
+    #[test]
+    fn load_rejects_an_unknown_key_name() {
+        let mut app = App::new();
+        app.insert_resource(ActionRegistry::<Label>::new());
+        app.world_mut()
+            .resource_mut::<ActionRegistry<Label>>()
+            .register_action("jump", noop);
+
+        let err = app
+            .load_keymaps_from_str::<Label>(
+                "[[keymaps]]\nkey = \"NotAKey\"\naction = \"jump\"\nlabel = \"Jump\"\n",
+            )
+            .unwrap_err();
+
+        assert!(matches!(err, KeymapConfigError::UnknownKey(key) if key == "NotAKey"));
+    }
+
+    #[test]
+    fn load_rejects_an_unregistered_action() {
+        let mut app = App::new();
+
+        let err = app
+            .load_keymaps_from_str::<Label>(
+                "[[keymaps]]\nkey = \"Space\"\naction = \"jump\"\nlabel = \"Jump\"\n",
+            )
+            .unwrap_err();
+
+        assert!(matches!(err, KeymapConfigError::UnknownAction(action) if action == "jump"));
+    }
+
+    #[test]
+    fn load_rejects_a_label_that_doesnt_parse() {
+        let mut app = App::new();
+        app.insert_resource(ActionRegistry::<Label>::new());
+        app.world_mut()
+            .resource_mut::<ActionRegistry<Label>>()
+            .register_action("jump", noop);
+
+        let err = app
+            .load_keymaps_from_str::<Label>(
+                "[[keymaps]]\nkey = \"Space\"\naction = \"jump\"\nlabel = \"NotALabel\"\n",
+            )
+            .unwrap_err();
+
+        assert!(matches!(err, KeymapConfigError::InvalidLabel(label) if label == "NotALabel"));
+    }
+
+    #[test]
+    fn load_rejects_the_same_action_bound_twice_without_losing_it_from_the_registry() {
+        let mut app = App::new();
+        app.insert_resource(ActionRegistry::<Label>::new());
+        app.world_mut()
+            .resource_mut::<ActionRegistry<Label>>()
+            .register_action("jump", noop);
+
+        let err = app
+            .load_keymaps_from_str::<Label>(
+                "[[keymaps]]\n\
+                 key = \"Space\"\n\
+                 action = \"jump\"\n\
+                 label = \"Jump\"\n\
+                 [[keymaps]]\n\
+                 key = \"Enter\"\n\
+                 action = \"jump\"\n\
+                 label = \"Jump\"\n",
+            )
+            .unwrap_err();
+        assert!(matches!(err, KeymapConfigError::DuplicateAction(action) if action == "jump"));
+
+        // A rejected config must not have consumed the action, so a corrected config
+        // using it once can still load successfully.
+        app.load_keymaps_from_str::<Label>(
+            "[[keymaps]]\nkey = \"Space\"\naction = \"jump\"\nlabel = \"Jump\"\n",
+        )
+        .unwrap();
+        assert_eq!(app.world().resource::<Keymapper<Label>>().keymaps.len(), 1);
+    }
+}
@@ -6,6 +6,11 @@
 //!
 //! This crate provides a simple and flexible way to map keyboard keys to Bevy systems. You can:
 //! - Bind multiple systems to different keys
+//! - Require modifier keys (Ctrl/Alt/Shift/Super) for a binding to fire
+//! - Bind a system to a chord of keys pressed in sequence (e.g. `g` then `g`)
+//! - Choose whether a binding fires on press, every frame while held, or on release
+//! - Load rebindable keymaps from a TOML config file via a named-action registry
+//! - Enumerate current bindings for a controls screen or command palette
 //! - Use custom labels to organize and manage keymaps
 //! - Dynamically add or remove key bindings at runtime
 //!
@@ -34,6 +39,85 @@
 //! ```
 
 use bevy::prelude::*;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+mod config;
+pub use config::{
+    ActionRegistry, KeymapConfig, KeymapConfigEntry, KeymapConfigError, KeymapperConfigAppExt,
+};
+
+/// A bitmask of keyboard modifier keys (Ctrl/Alt/Shift/Super).
+///
+/// Each flag matches either the left or right variant of the corresponding
+/// `KeyCode` (e.g. `ControlLeft` or `ControlRight`), so a keymap bound with
+/// `Modifiers::CTRL` fires for either control key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// No modifiers held.
+    pub const NONE: Modifiers = Modifiers(0);
+    /// Either `ControlLeft` or `ControlRight`.
+    pub const CTRL: Modifiers = Modifiers(1 << 0);
+    /// Either `AltLeft` or `AltRight`.
+    pub const ALT: Modifiers = Modifiers(1 << 1);
+    /// Either `ShiftLeft` or `ShiftRight`.
+    pub const SHIFT: Modifiers = Modifiers(1 << 2);
+    /// Either `SuperLeft` or `SuperRight`.
+    pub const SUPER: Modifiers = Modifiers(1 << 3);
+
+    /// Returns `true` if `self` contains every flag set in `other`.
+    pub fn contains(&self, other: Modifiers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Reads the current modifier state from the keyboard input resource.
+    fn current(input: &ButtonInput<KeyCode>) -> Self {
+        let mut mods = Modifiers::NONE;
+
+        if input.pressed(KeyCode::ControlLeft) || input.pressed(KeyCode::ControlRight) {
+            mods |= Modifiers::CTRL;
+        }
+        if input.pressed(KeyCode::AltLeft) || input.pressed(KeyCode::AltRight) {
+            mods |= Modifiers::ALT;
+        }
+        if input.pressed(KeyCode::ShiftLeft) || input.pressed(KeyCode::ShiftRight) {
+            mods |= Modifiers::SHIFT;
+        }
+        if input.pressed(KeyCode::SuperLeft) || input.pressed(KeyCode::SuperRight) {
+            mods |= Modifiers::SUPER;
+        }
+
+        mods
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, rhs: Modifiers) -> Modifiers {
+        Modifiers(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Modifiers) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// When a keymap's system should run relative to its key(s) being pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Trigger {
+    /// Run once, the frame the key(s) transition from released to pressed.
+    #[default]
+    JustPressed,
+    /// Run every frame while the key(s) are held down.
+    Pressed,
+    /// Run once, the frame the key(s) transition from pressed to released.
+    JustReleased,
+}
 
 /// A resource that manages a collection of keymaps.
 ///
@@ -47,6 +131,12 @@ use bevy::prelude::*;
 pub struct Keymapper<T: Send + Sync + 'static> {
     /// The collection of keymaps managed by this manager.
     pub keymaps: Vec<Keymap<T>>,
+    /// How long to wait between keypresses before the pending chord is abandoned.
+    pub timeout: Duration,
+    /// The keys accumulated so far while waiting for a multi-key chord to complete.
+    pending: Vec<KeyCode>,
+    /// When the last key was appended to `pending`.
+    last_input: Instant,
 }
 
 impl<T: PartialEq + Send + Sync + 'static> Keymapper<T> {
@@ -65,8 +155,55 @@ impl<T: PartialEq + Send + Sync + 'static> Keymapper<T> {
         self.keymaps.retain(|k| k.label != label);
     }
 
+    /// Returns every key binding as `(label, key sequence, modifiers)`.
+    ///
+    /// The key sequence is returned intact, in the order it must be pressed, so a
+    /// chord binding (e.g. `[KeyG, KeyG]`) reads as one ordered binding rather than
+    /// two unrelated single-key ones. Covers every keymap on this `Keymapper`, so a
+    /// "controls" screen or command palette built from this iterator won't silently
+    /// miss a binding.
+    pub fn bindings(&self) -> impl Iterator<Item = (&T, &[KeyCode], Modifiers)> {
+        self.keymaps
+            .iter()
+            .map(|keymap| (&keymap.label, keymap.keys.as_slice(), keymap.mods))
+    }
+
+    /// Returns every `(key sequence, modifiers)` pair bound to `label`.
+    ///
+    /// Like [`bindings`](Keymapper::bindings), each chord's key sequence is returned
+    /// intact rather than split into its individual keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label to look up bindings for.
+    pub fn keys_for(&self, label: &T) -> Vec<(&[KeyCode], Modifiers)> {
+        self.keymaps
+            .iter()
+            .filter(|keymap| &keymap.label == label)
+            .map(|keymap| (keymap.keys.as_slice(), keymap.mods))
+            .collect()
+    }
+
+    /// Builds a reverse lookup from key sequence to every label bound to it.
+    ///
+    /// Mirrors the palette/controls lookups editor keymaps expose: given the keys
+    /// someone just pressed (a single key, or a completed chord), what would that
+    /// do. Keyed by the whole sequence rather than by individual key, so a chord
+    /// isn't misrepresented as several independent single-key bindings.
+    pub fn reverse_map(&self) -> HashMap<&[KeyCode], Vec<&T>> {
+        let mut map: HashMap<&[KeyCode], Vec<&T>> = HashMap::new();
+        for keymap in &self.keymaps {
+            map.entry(keymap.keys.as_slice())
+                .or_default()
+                .push(&keymap.label);
+        }
+        map
+    }
+
     /// Creates a new `Keymapper` with the given keymaps.
     ///
+    /// The pending-chord timeout defaults to one second; change `timeout` to adjust it.
+    ///
     /// # Arguments
     ///
     /// * `keymaps` - A vector of keymaps to manage.
@@ -75,18 +212,91 @@ impl<T: PartialEq + Send + Sync + 'static> Keymapper<T> {
     ///
     /// A new `Keymapper` instance.
     pub fn new(keymaps: Vec<Keymap<T>>) -> Self {
-        Self { keymaps }
+        Self {
+            keymaps,
+            timeout: Duration::from_secs(1),
+            pending: Vec::new(),
+            last_input: Instant::now(),
+        }
     }
 
-    /// Executes all systems associated with the specified keycode.
+    /// Runs every `Trigger::Pressed` or `Trigger::JustReleased` keymap whose full key
+    /// sequence is a subset of `keycodes`, regardless of press order.
     ///
-    /// This method iterates through all keymaps and runs the systems for those
-    /// that match the given keycode. Systems are initialized on their first execution.
+    /// This is the counterpart to [`run`](Keymapper::run) for trigger modes where
+    /// the pending-chord state machine doesn't apply: a held binding should fire
+    /// every frame its keys are down, and a release binding should fire the instant
+    /// they're let go, neither of which cares about keypress ordering or timeout.
     ///
     /// # Arguments
     ///
     /// * `world` - The Bevy world to execute systems in.
-    /// * `keycode` - The keycode that was pressed.
+    /// * `keycodes` - The keys currently matching `trigger` (e.g. all held keys).
+    /// * `mods` - The modifier keys held at the time of the check.
+    /// * `trigger` - Only keymaps configured with this trigger are considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RunSystemError` if any system execution fails.
+    pub fn run_simultaneous(
+        &mut self,
+        world: &mut World,
+        keycodes: &[KeyCode],
+        mods: Modifiers,
+        trigger: Trigger,
+    ) -> Result<(), Box<bevy::ecs::system::RunSystemError>> {
+        for index in 0..self.keymaps.len() {
+            let matches = {
+                let keymap = &self.keymaps[index];
+                keymap.trigger == trigger
+                    && keymap.mods == mods
+                    && keymap.keys.iter().all(|key| keycodes.contains(key))
+            };
+            if !matches {
+                continue;
+            }
+
+            let keymap = &mut self.keymaps[index];
+            if !keymap.initialized {
+                keymap.system.initialize(world);
+                keymap.initialized = true;
+            }
+
+            keymap.system.run((), world)?;
+            keymap.system.apply_deferred(world);
+        }
+
+        Ok(())
+    }
+
+    /// Advances the chord-matching state machine with a single just-pressed key.
+    ///
+    /// Only considers keymaps configured with `Trigger::JustPressed`; see
+    /// [`run_simultaneous`](Keymapper::run_simultaneous) for `Pressed`/`JustReleased` keymaps.
+    /// The key is appended to the pending sequence (which is first cleared if `now`
+    /// is more than `timeout` past the previous keypress). If the pending sequence
+    /// exactly matches a keymap's key sequence, that keymap's system runs and the
+    /// buffer is cleared. If it's a strict prefix of at least one keymap's sequence,
+    /// the buffer is kept so later keys can complete the chord. Otherwise the buffer
+    /// is cleared and `keycode` is retried alone as a fresh start, so a key that
+    /// matches nothing never swallows the next keypress.
+    ///
+    /// Call this once per just-pressed key, in the order the keys were actually
+    /// pressed. [`keymaps_runner_system`] sources that order from
+    /// `ButtonInput::get_just_pressed`, which is backed by an unordered set, so two
+    /// keys that both become just-pressed within the same frame are not guaranteed
+    /// to be reported (and therefore chorded) in the order they were physically
+    /// pressed. This only matters for chords whose first two keys can plausibly be
+    /// pressed in the same frame; single-key bindings and modifier-gated bindings
+    /// are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - The Bevy world to execute systems in.
+    /// * `keycode` - The keycode that was just pressed.
+    /// * `mods` - The modifier keys held at the time `keycode` was pressed. A keymap
+    ///   only fires when its own modifier mask exactly matches this value.
+    /// * `now` - The current time, used to expire a stale pending chord.
     ///
     /// # Returns
     ///
@@ -99,27 +309,100 @@ impl<T: PartialEq + Send + Sync + 'static> Keymapper<T> {
         &mut self,
         world: &mut World,
         keycode: KeyCode,
+        mods: Modifiers,
+        now: Instant,
     ) -> Result<(), Box<bevy::ecs::system::RunSystemError>> {
-        for keymap in &mut self.keymaps {
-            if keymap.keycode == keycode {
-                if !keymap.initialized {
-                    keymap.system.initialize(world);
-                    keymap.initialized = true;
-                }
-
-                keymap.system.run((), world)?;
-                keymap.system.apply_deferred(world);
-            }
+        if now.duration_since(self.last_input) > self.timeout {
+            self.pending.clear();
+        }
+        self.last_input = now;
+
+        self.pending.push(keycode);
+        if self.dispatch_pending(world, mods)? {
+            return Ok(());
+        }
+        if self.pending_is_prefix(mods) {
+            return Ok(());
+        }
+
+        // Nothing matched or could still match; retry with just the new key.
+        self.pending.clear();
+        self.pending.push(keycode);
+        if self.dispatch_pending(world, mods)? {
+            return Ok(());
+        }
+        if !self.pending_is_prefix(mods) {
+            self.pending.clear();
         }
 
         Ok(())
     }
+
+    /// Returns `true` if `pending` is a strict prefix of some `Trigger::JustPressed`
+    /// keymap's key sequence under `mods`.
+    fn pending_is_prefix(&self, mods: Modifiers) -> bool {
+        self.keymaps.iter().any(|keymap| {
+            keymap.trigger == Trigger::JustPressed
+                && keymap.mods == mods
+                && keymap.keys.len() > self.pending.len()
+                && keymap.keys[..self.pending.len()] == self.pending[..]
+        })
+    }
+
+    /// Runs every `Trigger::JustPressed` keymap whose key sequence exactly matches
+    /// `pending` under `mods` (mirroring [`run_simultaneous`](Keymapper::run_simultaneous),
+    /// which likewise fires every match rather than only the first), clearing `pending`
+    /// if any did. Returns whether at least one match was found.
+    fn dispatch_pending(
+        &mut self,
+        world: &mut World,
+        mods: Modifiers,
+    ) -> Result<bool, Box<bevy::ecs::system::RunSystemError>> {
+        let mut matched = false;
+
+        for index in 0..self.keymaps.len() {
+            let matches = {
+                let keymap = &self.keymaps[index];
+                keymap.trigger == Trigger::JustPressed
+                    && keymap.keys == self.pending
+                    && keymap.mods == mods
+            };
+            if !matches {
+                continue;
+            }
+            matched = true;
+
+            let keymap = &mut self.keymaps[index];
+            if !keymap.initialized {
+                keymap.system.initialize(world);
+                keymap.initialized = true;
+            }
+
+            keymap.system.run((), world)?;
+            keymap.system.apply_deferred(world);
+        }
+
+        if matched {
+            self.pending.clear();
+        }
+        Ok(matched)
+    }
 }
 
-/// A mapping between a keyboard key and a Bevy system.
+/// A mapping between a keyboard key (or key sequence) and a Bevy system.
+///
+/// Each keymap associates a label, a sequence of one or more keycodes, and a system
+/// that should be executed once that sequence has been typed in order (for the default
+/// `Trigger::JustPressed`; `Pressed`/`JustReleased` keymaps match when all of their keys
+/// are held/released together, in any order). A single-key binding is just a sequence of
+/// length one. The system is lazily initialized on
+/// first execution.
 ///
-/// Each keymap associates a label, a keycode, and a system that should be executed
-/// when the key is pressed. The system is lazily initialized on first execution.
+/// `KeyCode` already identifies the physical key position in this Bevy version (the
+/// printed-label/layout-aware key is the separate `Key` type, surfaced through
+/// `KeyboardInput` events rather than a `ButtonInput` resource), so a binding like
+/// `KeyCode::KeyW` stays on the same physical key across QWERTY/AZERTY/Dvorak layouts
+/// with no extra work.
 ///
 /// # Type Parameters
 ///
@@ -127,8 +410,13 @@ impl<T: PartialEq + Send + Sync + 'static> Keymapper<T> {
 pub struct Keymap<T> {
     /// The label identifying this keymap.
     pub label: T,
-    /// The keyboard key that triggers this keymap.
-    pub keycode: KeyCode,
+    /// The sequence of keys that triggers this keymap, in the order they must be pressed
+    /// (order is ignored for `Trigger::Pressed` and `Trigger::JustReleased`).
+    pub keys: Vec<KeyCode>,
+    /// The modifier keys (Ctrl/Alt/Shift/Super) that must be held for this keymap to fire.
+    pub mods: Modifiers,
+    /// When the system should run relative to the key(s) being pressed.
+    pub trigger: Trigger,
     /// The system to execute when the key is pressed.
     pub system: Box<dyn System<In = (), Out = ()>>,
     /// Whether the system has been initialized.
@@ -155,20 +443,122 @@ impl<T> Keymap<T> {
     /// let keymap = Keymap::new(KeymapLabel::Jump, KeyCode::Space, jump_system);
     /// ```
     pub fn new<M>(label: T, keycode: KeyCode, system: impl IntoSystem<(), (), M>) -> Self {
+        Self::new_with_mods(label, keycode, Modifiers::NONE, system)
+    }
+
+    /// Creates a new keymap binding a label, keycode and modifier mask to a system.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap.
+    /// * `keycode` - The keyboard key that will trigger the system.
+    /// * `mods` - The modifier keys that must be held alongside `keycode`.
+    /// * `system` - The system to execute when the key is pressed.
+    ///
+    /// # Returns
+    ///
+    /// A new `Keymap` instance.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let keymap = Keymap::new_with_mods(KeymapLabel::Save, KeyCode::KeyS, Modifiers::CTRL, save_system);
+    /// ```
+    pub fn new_with_mods<M>(
+        label: T,
+        keycode: KeyCode,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> Self {
+        Self::new_with_keys(label, vec![keycode], mods, system)
+    }
+
+    /// Creates a new keymap binding a label and a multi-key chord to a system.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap.
+    /// * `keys` - The sequence of keys that must be pressed in order, e.g. `g` then `g`.
+    /// * `mods` - The modifier keys that must be held while the sequence is typed.
+    /// * `system` - The system to execute once the sequence completes.
+    ///
+    /// # Returns
+    ///
+    /// A new `Keymap` instance.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let keymap = Keymap::new_with_keys(
+    ///     KeymapLabel::GotoTop,
+    ///     vec![KeyCode::KeyG, KeyCode::KeyG],
+    ///     Modifiers::NONE,
+    ///     goto_top_system,
+    /// );
+    /// ```
+    pub fn new_with_keys<M>(
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> Self {
+        Self::new_with_trigger(label, keys, mods, Trigger::JustPressed, system)
+    }
+
+    /// Creates a new keymap with full control over its key sequence, modifiers and trigger.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap.
+    /// * `keys` - The sequence of keys that must be pressed in order.
+    /// * `mods` - The modifier keys that must be held while the sequence is typed.
+    /// * `trigger` - Whether to run on just-pressed, while held, or on release.
+    /// * `system` - The system to execute.
+    ///
+    /// # Returns
+    ///
+    /// A new `Keymap` instance.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let keymap = Keymap::new_with_trigger(
+    ///     KeymapLabel::MoveForward,
+    ///     vec![KeyCode::KeyW],
+    ///     Modifiers::NONE,
+    ///     Trigger::Pressed,
+    ///     move_forward_system,
+    /// );
+    /// ```
+    pub fn new_with_trigger<M>(
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        trigger: Trigger,
+        system: impl IntoSystem<(), (), M>,
+    ) -> Self {
         Self {
             label,
-            keycode,
+            keys,
+            mods,
+            trigger,
             system: Box::new(IntoSystem::into_system(system)),
             initialized: false,
         }
     }
 }
 
-/// A system that runs all keymaps for just-pressed keys.
+/// A system that runs all keymaps for the current frame's keyboard input.
 ///
 /// This system should be added to your Bevy app to enable keymap functionality.
 /// It checks for keyboard input every frame and executes the systems associated
-/// with any keys that were just pressed.
+/// with any keys matching their keymap's trigger: `JustPressed` keymaps run once
+/// on the press (advancing the chord state machine), `Pressed` keymaps run every
+/// frame their keys are held, and `JustReleased` keymaps run once on release.
+///
+/// `JustPressed` keys are read from `ButtonInput::get_just_pressed`, an unordered
+/// set, so see [`Keymapper::run`] for the resulting limitation on chords whose
+/// first two keys can both become just-pressed in the same frame.
 ///
 /// # Type Parameters
 ///
@@ -189,15 +579,21 @@ where
     T: Send + Sync + PartialEq + 'static,
 {
     let keyboard_input = world.resource::<ButtonInput<KeyCode>>().clone();
-    let keycodes: Vec<KeyCode> = keyboard_input.get_just_pressed().copied().collect();
+    let just_pressed: Vec<KeyCode> = keyboard_input.get_just_pressed().copied().collect();
+    let pressed: Vec<KeyCode> = keyboard_input.get_pressed().copied().collect();
+    let just_released: Vec<KeyCode> = keyboard_input.get_just_released().copied().collect();
+    let mods = Modifiers::current(&keyboard_input);
+    let now = Instant::now();
 
     let result = world.resource_scope(
         |world,
          mut manager: Mut<Keymapper<T>>|
          -> Result<(), Box<bevy::ecs::system::RunSystemError>> {
-            for keycode in keycodes {
-                manager.run(world, keycode)?;
+            for keycode in just_pressed {
+                manager.run(world, keycode, mods, now)?;
             }
+            manager.run_simultaneous(world, &pressed, mods, Trigger::Pressed)?;
+            manager.run_simultaneous(world, &just_released, mods, Trigger::JustReleased)?;
 
             Ok(())
         },
@@ -243,6 +639,114 @@ pub trait KeymapperAppExt {
     ) -> &mut Self
     where
         T: Send + Sync + PartialEq + 'static;
+
+    /// Adds a keymap binding that additionally requires a set of modifier keys.
+    ///
+    /// This behaves like [`add_keymap`](KeymapperAppExt::add_keymap), but the system only
+    /// runs when the held modifier keys (Ctrl/Alt/Shift/Super) exactly match `mods`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap. Can be used later to remove the keymap.
+    /// * `keycode` - The keyboard key that will trigger the system.
+    /// * `mods` - The modifier keys that must be held alongside `keycode`.
+    /// * `system` - The system to execute when the key is pressed.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `App` for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// app.add_keymap_with_mods(KeymapLabel::Save, KeyCode::KeyS, Modifiers::CTRL, save_system);
+    /// ```
+    fn add_keymap_with_mods<M, T>(
+        &mut self,
+        label: T,
+        keycode: KeyCode,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static;
+
+    /// Adds a keymap binding that triggers on a sequence of keys, e.g. `g` then `g`.
+    ///
+    /// The system only runs once the keys in `keys` have been pressed in order
+    /// within the `Keymapper`'s configured timeout of each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap. Can be used later to remove the keymap.
+    /// * `keys` - The sequence of keys that must be pressed in order.
+    /// * `mods` - The modifier keys that must be held while the sequence is typed.
+    /// * `system` - The system to execute once the sequence completes.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `App` for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// app.add_keymap_with_keys(
+    ///     KeymapLabel::GotoTop,
+    ///     vec![KeyCode::KeyG, KeyCode::KeyG],
+    ///     Modifiers::NONE,
+    ///     goto_top_system,
+    /// );
+    /// ```
+    fn add_keymap_with_keys<M, T>(
+        &mut self,
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static;
+
+    /// Adds a keymap binding with full control over its keys, modifiers and trigger.
+    ///
+    /// Use `Trigger::Pressed` for "hold to run" bindings (e.g. movement) or
+    /// `Trigger::JustReleased` to fire when the key(s) are let go, instead of the
+    /// default `Trigger::JustPressed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A label to identify this keymap. Can be used later to remove the keymap.
+    /// * `keys` - The sequence of keys that must be pressed in order (or simultaneously,
+    ///   for `Pressed`/`JustReleased`).
+    /// * `mods` - The modifier keys that must be held alongside `keys`.
+    /// * `trigger` - Whether to run on just-pressed, while held, or on release.
+    /// * `system` - The system to execute.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `App` for method chaining.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// app.add_keymap_with_trigger(
+    ///     MoveKeymaps::Forward,
+    ///     vec![KeyCode::KeyW],
+    ///     Modifiers::NONE,
+    ///     Trigger::Pressed,
+    ///     move_forward_system,
+    /// );
+    /// ```
+    fn add_keymap_with_trigger<M, T>(
+        &mut self,
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        trigger: Trigger,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static;
 }
 
 impl KeymapperAppExt for App {
@@ -252,6 +756,46 @@ impl KeymapperAppExt for App {
         keycode: KeyCode,
         system: impl IntoSystem<(), (), M>,
     ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static,
+    {
+        self.add_keymap_with_mods(label, keycode, Modifiers::NONE, system)
+    }
+
+    fn add_keymap_with_mods<M, T>(
+        &mut self,
+        label: T,
+        keycode: KeyCode,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static,
+    {
+        self.add_keymap_with_keys(label, vec![keycode], mods, system)
+    }
+
+    fn add_keymap_with_keys<M, T>(
+        &mut self,
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
+    where
+        T: Send + Sync + PartialEq + 'static,
+    {
+        self.add_keymap_with_trigger(label, keys, mods, Trigger::JustPressed, system)
+    }
+
+    fn add_keymap_with_trigger<M, T>(
+        &mut self,
+        label: T,
+        keys: Vec<KeyCode>,
+        mods: Modifiers,
+        trigger: Trigger,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self
     where
         T: Send + Sync + PartialEq + 'static,
     {
@@ -260,8 +804,268 @@ impl KeymapperAppExt for App {
         }
 
         let mut manager = self.world_mut().resource_mut::<Keymapper<T>>();
-        manager.keymaps.push(Keymap::new(label, keycode, system));
+        manager
+            .keymaps
+            .push(Keymap::new_with_trigger(label, keys, mods, trigger, system));
 
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Hits(u32);
+
+    fn incr(mut hits: ResMut<Hits>) {
+        hits.0 += 1;
+    }
+
+    fn hits(world: &World) -> u32 {
+        world.resource::<Hits>().0
+    }
+
+    #[test]
+    fn run_fires_on_exact_single_key_match() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new("jump", KeyCode::Space, incr)]);
+
+        manager
+            .run(&mut world, KeyCode::Space, Modifiers::NONE, Instant::now())
+            .unwrap();
+
+        assert_eq!(hits(&world), 1);
+        assert!(manager.pending.is_empty());
+    }
+
+    #[test]
+    fn run_fires_only_once_the_full_chord_is_pressed() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_keys(
+            "goto_top",
+            vec![KeyCode::KeyG, KeyCode::KeyG],
+            Modifiers::NONE,
+            incr,
+        )]);
+        let now = Instant::now();
+
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, now)
+            .unwrap();
+        assert_eq!(
+            hits(&world),
+            0,
+            "first key of the chord should not fire yet"
+        );
+
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, now)
+            .unwrap();
+        assert_eq!(hits(&world), 1, "second key should complete the chord");
+        assert!(manager.pending.is_empty());
+    }
+
+    #[test]
+    fn pending_chord_expires_after_the_timeout() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_keys(
+            "goto_top",
+            vec![KeyCode::KeyG, KeyCode::KeyG],
+            Modifiers::NONE,
+            incr,
+        )]);
+        manager.timeout = Duration::from_millis(10);
+        let first_press = Instant::now();
+
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, first_press)
+            .unwrap();
+
+        let too_late = first_press + Duration::from_millis(20);
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, too_late)
+            .unwrap();
+
+        assert_eq!(
+            hits(&world),
+            0,
+            "a stale pending chord should be dropped instead of completed"
+        );
+    }
+
+    #[test]
+    fn an_unmatched_key_does_not_swallow_the_next_press() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new("jump", KeyCode::Space, incr)]);
+        let now = Instant::now();
+
+        manager
+            .run(&mut world, KeyCode::KeyX, Modifiers::NONE, now)
+            .unwrap();
+        assert_eq!(hits(&world), 0);
+        assert!(manager.pending.is_empty());
+
+        manager
+            .run(&mut world, KeyCode::Space, Modifiers::NONE, now)
+            .unwrap();
+        assert_eq!(
+            hits(&world),
+            1,
+            "the unmatched key shouldn't have prevented the next press from firing"
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct OtherHits(u32);
+
+    fn incr_other(mut hits: ResMut<OtherHits>) {
+        hits.0 += 1;
+    }
+
+    #[test]
+    fn run_fires_every_keymap_matching_the_same_key_and_mods() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        world.insert_resource(OtherHits::default());
+        let mut manager = Keymapper::new(vec![
+            Keymap::new("jump", KeyCode::Space, incr),
+            Keymap::new("shoot", KeyCode::Space, incr_other),
+        ]);
+
+        manager
+            .run(&mut world, KeyCode::Space, Modifiers::NONE, Instant::now())
+            .unwrap();
+
+        assert_eq!(hits(&world), 1, "first keymap bound to the key should fire");
+        assert_eq!(
+            world.resource::<OtherHits>().0,
+            1,
+            "second keymap bound to the same key should also fire, not be shadowed"
+        );
+    }
+
+    #[test]
+    fn pending_is_not_held_open_by_a_prefix_whose_mods_dont_match() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_keys(
+            "goto_top",
+            vec![KeyCode::KeyG, KeyCode::KeyG],
+            Modifiers::CTRL,
+            incr,
+        )]);
+
+        // An unmodified `G` can never complete a Ctrl+G,G chord, so it must not be
+        // treated as a pending prefix either.
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, Instant::now())
+            .unwrap();
+
+        assert!(
+            manager.pending.is_empty(),
+            "a key that can't complete any chord under its own mods shouldn't stay pending"
+        );
+    }
+
+    #[test]
+    fn simultaneous_just_pressed_keys_chord_in_call_order_not_press_order() {
+        // `keymaps_runner_system` feeds `run` from `ButtonInput::get_just_pressed`, an
+        // unordered set, so two keys that both become just-pressed in the same frame
+        // are only chorded if `run` happens to be called in the order they were
+        // physically pressed. This test locks in and documents that limitation.
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_keys(
+            "goto_top",
+            vec![KeyCode::KeyG, KeyCode::KeyX],
+            Modifiers::NONE,
+            incr,
+        )]);
+        let now = Instant::now();
+
+        // Physically, G was pressed before X, but the unordered set reports X first.
+        manager
+            .run(&mut world, KeyCode::KeyX, Modifiers::NONE, now)
+            .unwrap();
+        manager
+            .run(&mut world, KeyCode::KeyG, Modifiers::NONE, now)
+            .unwrap();
+
+        assert_eq!(
+            hits(&world),
+            0,
+            "calling run() out of physical press order can miss the chord"
+        );
+    }
+
+    #[test]
+    fn run_simultaneous_fires_every_frame_the_keys_are_held() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_trigger(
+            "move_forward",
+            vec![KeyCode::KeyW],
+            Modifiers::NONE,
+            Trigger::Pressed,
+            incr,
+        )]);
+
+        manager
+            .run_simultaneous(
+                &mut world,
+                &[KeyCode::KeyW],
+                Modifiers::NONE,
+                Trigger::Pressed,
+            )
+            .unwrap();
+        manager
+            .run_simultaneous(
+                &mut world,
+                &[KeyCode::KeyW],
+                Modifiers::NONE,
+                Trigger::Pressed,
+            )
+            .unwrap();
+
+        assert_eq!(
+            hits(&world),
+            2,
+            "a Pressed keymap should fire every held frame"
+        );
+    }
+
+    #[test]
+    fn run_simultaneous_does_not_fire_when_a_required_key_is_missing() {
+        let mut world = World::new();
+        world.insert_resource(Hits::default());
+        let mut manager = Keymapper::new(vec![Keymap::new_with_trigger(
+            "strafe",
+            vec![KeyCode::ShiftLeft, KeyCode::KeyA],
+            Modifiers::NONE,
+            Trigger::Pressed,
+            incr,
+        )]);
+
+        // Only one of the two required keys is held.
+        manager
+            .run_simultaneous(
+                &mut world,
+                &[KeyCode::KeyA],
+                Modifiers::NONE,
+                Trigger::Pressed,
+            )
+            .unwrap();
+
+        assert_eq!(
+            hits(&world),
+            0,
+            "a Pressed keymap shouldn't fire unless every one of its keys is held"
+        );
+    }
+}